@@ -0,0 +1,142 @@
+use crate::mdc_sys::MDCDrawer;
+use wasm_bindgen::prelude::*;
+use web_sys::Element;
+use yew::prelude::*;
+
+pub mod content;
+pub mod header;
+pub mod subtitle;
+pub mod title;
+
+pub use content::Content;
+pub use header::Header;
+pub use subtitle::Subtitle;
+pub use title::Title;
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum DrawerVariant {
+    Permanent,
+    Dismissible,
+    Modal,
+}
+
+impl Default for DrawerVariant {
+    fn default() -> Self {
+        DrawerVariant::Permanent
+    }
+}
+
+pub struct Drawer {
+    opened_callback: Closure<dyn FnMut(web_sys::Event)>,
+    closed_callback: Closure<dyn FnMut(web_sys::Event)>,
+    inner: Option<MDCDrawer>,
+    node_ref: NodeRef,
+    current_open: bool,
+}
+
+#[derive(Properties, Clone, PartialEq)]
+pub struct DrawerProps {
+    pub children: Children,
+    #[prop_or_default]
+    pub id: String,
+    #[prop_or_default]
+    pub variant: DrawerVariant,
+    #[prop_or_default]
+    pub open: bool,
+    #[prop_or_else(Callback::noop)]
+    pub onopened: Callback<()>,
+    #[prop_or_else(Callback::noop)]
+    pub onclosed: Callback<()>,
+}
+
+pub enum DrawerMsg {
+    Opened,
+    Closed,
+}
+
+impl Component for Drawer {
+    type Message = DrawerMsg;
+    type Properties = DrawerProps;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        let opened = ctx.link().callback(|_| DrawerMsg::Opened);
+        let opened_callback = Closure::wrap(Box::new(move |_: web_sys::Event| {
+            opened.emit(());
+        }) as Box<dyn FnMut(web_sys::Event)>);
+
+        let closed = ctx.link().callback(|_| DrawerMsg::Closed);
+        let closed_callback = Closure::wrap(Box::new(move |_: web_sys::Event| {
+            closed.emit(());
+        }) as Box<dyn FnMut(web_sys::Event)>);
+
+        Self {
+            opened_callback,
+            closed_callback,
+            inner: None,
+            node_ref: NodeRef::default(),
+            current_open: ctx.props().open,
+        }
+    }
+
+    fn changed(&mut self, ctx: &Context<Self>) -> bool {
+        if ctx.props().open != self.current_open {
+            self.current_open = ctx.props().open;
+            if let Some(drawer) = &self.inner {
+                drawer.set_open(self.current_open);
+            }
+        }
+        true
+    }
+
+    fn rendered(&mut self, ctx: &Context<Self>, first_render: bool) {
+        if first_render {
+            if let Some(elem) = self.node_ref.cast::<Element>() {
+                let drawer = MDCDrawer::new(elem);
+                drawer.set_open(self.current_open);
+                drawer.listen("MDCDrawer:opened", &self.opened_callback);
+                drawer.listen("MDCDrawer:closed", &self.closed_callback);
+
+                self.inner = Some(drawer);
+            }
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            DrawerMsg::Opened => ctx.props().onopened.emit(()),
+            DrawerMsg::Closed => ctx.props().onclosed.emit(()),
+        }
+        false
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let classes = classes![
+            "mdc-drawer",
+            match ctx.props().variant {
+                DrawerVariant::Permanent => None,
+                DrawerVariant::Dismissible => Some("mdc-drawer--dismissible"),
+                DrawerVariant::Modal => Some("mdc-drawer--modal"),
+            }
+        ];
+        let scrim = match ctx.props().variant {
+            DrawerVariant::Modal => html! { <div class="mdc-drawer-scrim"></div> },
+            _ => html! {},
+        };
+        html! {
+            <>
+                <aside id={ctx.props().id.clone()} class={classes} ref={self.node_ref.clone()}>
+                    { ctx.props().children.clone() }
+                </aside>
+                { scrim }
+            </>
+        }
+    }
+
+    fn destroy(&mut self, _ctx: &Context<Self>) {
+        if let Some(inner) = &self.inner {
+            inner.unlisten("MDCDrawer:opened", &self.opened_callback);
+            inner.unlisten("MDCDrawer:closed", &self.closed_callback);
+            inner.destroy();
+        }
+    }
+}
@@ -0,0 +1,35 @@
+use yew::prelude::*;
+
+pub struct Title;
+
+#[derive(Properties, Clone, PartialEq)]
+pub struct Props {
+    #[prop_or_default]
+    pub id: String,
+    pub children: Children,
+}
+
+impl Component for Title {
+    type Message = ();
+    type Properties = Props;
+
+    fn create(_ctx: &Context<Self>) -> Self {
+        Self
+    }
+
+    fn changed(&mut self, _ctx: &Context<Self>) -> bool {
+        true
+    }
+
+    fn update(&mut self, _ctx: &Context<Self>, _msg: Self::Message) -> bool {
+        false
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        html! {
+            <h3 class="mdc-drawer__title" id={ctx.props().id.clone()}>
+                { ctx.props().children.clone() }
+            </h3>
+        }
+    }
+}
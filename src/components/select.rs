@@ -1,22 +1,42 @@
 use crate::mdc_sys::MDCSelect;
 use boolinator::Boolinator;
 use serde::{Deserialize, Serialize};
+use std::marker::PhantomData;
+use std::str::FromStr;
 use wasm_bindgen::prelude::*;
 use web_sys::Element;
 use yew::prelude::*;
 
 pub mod item;
-pub use item::Item;
+pub use item::{GraphicType, Item};
 
-pub struct Select {
+pub struct Select<T>
+where
+    T: Clone + PartialEq + ToString + FromStr + 'static,
+{
     changed_callback: Closure<dyn FnMut(web_sys::CustomEvent)>,
     inner: Option<MDCSelect>,
     node_ref: NodeRef,
+    validity: ValidityState,
+    selected_values: Vec<String>,
+    current_value: Option<String>,
+    /// Set right before a programmatic `select.set_value(...)` call, since
+    /// MDCSelect re-fires `MDCSelect:change` for that write; checked (and
+    /// cleared) in `SelectMsg::Changed` so it isn't echoed back out as if
+    /// the user had picked it.
+    suppress_next_change: bool,
+    _value: PhantomData<T>,
 }
 
+/// A [`Select`] bound to raw `String` values, matching the pre-generic API.
+pub type StringSelect = Select<String>;
+
 #[derive(Properties, Clone, PartialEq)]
-pub struct SelectProps {
-    pub children: Children,
+pub struct SelectProps<T>
+where
+    T: Clone + PartialEq + ToString + FromStr + 'static,
+{
+    pub children: ChildrenWithProps<Item>,
     pub select_width_class: String,
     pub id: String,
     #[prop_or_default]
@@ -25,10 +45,32 @@ pub struct SelectProps {
     pub fixed_position: bool,
     #[prop_or_default]
     pub absolute_position: Option<(i32, i32)>,
+    /// Fires with the raw JS change detail on every pick, unchanged from the
+    /// pre-generic API so existing `Select` consumers keep compiling.
     #[prop_or_else(Callback::noop)]
     pub onchange: Callback<SelectChangeEventData>,
+    /// Fires with `data.value`/`data.index` parsed into `T`, falling back to
+    /// the raw index when parsing the value fails.
+    #[prop_or_else(Callback::noop)]
+    pub onvalue: Callback<T>,
+    #[prop_or_default]
+    pub selected_value: Option<T>,
     #[prop_or_default]
-    pub selected_value: Option<String>,
+    pub required: bool,
+    #[prop_or_default]
+    pub validation_message: Option<String>,
+    #[prop_or_default]
+    pub validity_transform: Option<ValidityTransform>,
+    #[prop_or_else(Callback::noop)]
+    pub onvalidity: Callback<ValidityState>,
+    #[prop_or_default]
+    pub multiple: bool,
+    #[prop_or_default]
+    pub selected_values: Option<Vec<String>>,
+    #[prop_or_else(Callback::noop)]
+    pub onchange_multi: Callback<Vec<SelectChangeEventData>>,
+    #[prop_or_default]
+    pub leading_icon: Option<&'static str>,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -37,16 +79,98 @@ pub struct SelectChangeEventData {
     pub index: i64,
 }
 
+/// Mirrors the subset of the browser `ValidityState` we can derive without
+/// a real `<select>` element backing the MDC widget.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+pub struct NativeValidityState {
+    pub value_missing: bool,
+}
+
+/// Overridable validity result, analogous to ymc select's `ValidityState`.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ValidityState {
+    pub value_missing: bool,
+    pub custom_error: bool,
+    pub valid: bool,
+}
+
+impl Default for ValidityState {
+    fn default() -> Self {
+        Self {
+            value_missing: false,
+            custom_error: false,
+            valid: true,
+        }
+    }
+}
+
+impl From<NativeValidityState> for ValidityState {
+    fn from(native: NativeValidityState) -> Self {
+        Self {
+            value_missing: native.value_missing,
+            custom_error: false,
+            valid: !native.value_missing,
+        }
+    }
+}
+
+pub type ValidityTransform = Callback<(String, NativeValidityState), ValidityState>;
+
+/// Adds `value` to a multi-select set, if not already present.
+pub fn add_selected_value(values: &mut Vec<String>, value: String) {
+    if !values.contains(&value) {
+        values.push(value);
+    }
+}
+
+/// Removes `value` from a multi-select set, if present.
+pub fn remove_selected_value(values: &mut Vec<String>, value: &str) {
+    values.retain(|existing| existing != value);
+}
+
 pub enum SelectMsg {
     Changed(SelectChangeEventData),
+    ChangedMulti(Vec<SelectChangeEventData>),
+    CheckValidity,
 }
 
-impl Component for Select {
+impl<T> Select<T>
+where
+    T: Clone + PartialEq + ToString + FromStr + 'static,
+{
+    fn native_validity(ctx: &Context<Self>, value: &str) -> NativeValidityState {
+        NativeValidityState {
+            value_missing: ctx.props().required && value.is_empty(),
+        }
+    }
+
+    fn compute_validity(ctx: &Context<Self>, value: &str) -> ValidityState {
+        let native = Self::native_validity(ctx, value);
+        match &ctx.props().validity_transform {
+            Some(transform) => transform.emit((value.to_string(), native)),
+            None => ValidityState::from(native),
+        }
+    }
+
+    fn apply_validity(&mut self, ctx: &Context<Self>, value: &str) {
+        self.validity = Self::compute_validity(ctx, value);
+        if let Some(select) = &self.inner {
+            select.set_valid(self.validity.valid);
+        }
+        ctx.props().onvalidity.emit(self.validity.clone());
+    }
+
+}
+
+impl<T> Component for Select<T>
+where
+    T: Clone + PartialEq + ToString + FromStr + 'static,
+{
     type Message = SelectMsg;
-    type Properties = SelectProps;
+    type Properties = SelectProps<T>;
 
     fn create(ctx: &Context<Self>) -> Self {
-        let callback = ctx.link().callback(|data| SelectMsg::Changed(data));
+        let callback = ctx.link().callback(SelectMsg::Changed);
         let closure = Closure::wrap(Box::new(move |e: web_sys::CustomEvent| {
             e.stop_propagation();
             let event_data = e.detail().into_serde::<SelectChangeEventData>().expect(
@@ -58,10 +182,36 @@ impl Component for Select {
             changed_callback: closure,
             inner: None,
             node_ref: NodeRef::default(),
+            validity: ValidityState::default(),
+            selected_values: ctx.props().selected_values.clone().unwrap_or_default(),
+            current_value: None,
+            suppress_next_change: false,
+            _value: PhantomData,
         }
     }
 
-    fn changed(&mut self, _ctx: &Context<Self>) -> bool {
+    fn changed(&mut self, ctx: &Context<Self>) -> bool {
+        if ctx.props().multiple {
+            // Reconcile against the `selected_values` prop by re-rendering
+            // each `Item` with the right `selected` state, rather than
+            // fighting MDCSelect's single-value DOM API.
+            if let Some(values) = ctx.props().selected_values.clone() {
+                self.selected_values = values;
+            }
+        } else {
+            let new_value = ctx
+                .props()
+                .selected_value
+                .clone()
+                .map(|value| value.to_string());
+            if new_value != self.current_value {
+                if let Some(select) = &self.inner {
+                    self.suppress_next_change = true;
+                    select.set_value(new_value.clone().unwrap_or_default().as_str());
+                }
+                self.current_value = new_value;
+            }
+        }
         true
     }
 
@@ -69,30 +219,104 @@ impl Component for Select {
         if first_render {
             if let Some(elem) = self.node_ref.cast::<Element>() {
                 let select = MDCSelect::new(elem);
-                if let Some(selected_value) = ctx.props().selected_value.clone() {
-                    select.set_value(selected_value.as_str());
+                if !ctx.props().multiple {
+                    if let Some(selected_value) = ctx.props().selected_value.clone() {
+                        select.set_value(selected_value.to_string().as_str());
+                    }
                 }
                 select.listen("MDCSelect:change", &self.changed_callback);
 
                 self.inner = Some(select);
             }
+            let value = ctx
+                .props()
+                .selected_value
+                .clone()
+                .map(|value| value.to_string());
+            self.current_value = value.clone();
+            self.apply_validity(ctx, &value.unwrap_or_default());
         }
     }
 
     fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
         match msg {
-            SelectMsg::Changed(data) => ctx.props().onchange.emit(data),
+            SelectMsg::Changed(data) => {
+                if self.suppress_next_change {
+                    self.suppress_next_change = false;
+                    return false;
+                }
+                self.apply_validity(ctx, &data.value);
+                self.current_value = Some(data.value.clone());
+                ctx.props().onchange.emit(SelectChangeEventData {
+                    value: data.value.clone(),
+                    index: data.index,
+                });
+                if ctx.props().multiple {
+                    // The underlying MDCSelect is natively single-select and
+                    // only ever reports one picked row at a time, so we track
+                    // the accumulated set ourselves and toggle membership on
+                    // every pick rather than trusting a DOM-reported list.
+                    if self.selected_values.contains(&data.value) {
+                        remove_selected_value(&mut self.selected_values, &data.value);
+                    } else {
+                        add_selected_value(&mut self.selected_values, data.value.clone());
+                    }
+                    let events = self
+                        .selected_values
+                        .iter()
+                        .cloned()
+                        .map(|value| SelectChangeEventData {
+                            value,
+                            index: data.index,
+                        })
+                        .collect();
+                    ctx.link().send_message(SelectMsg::ChangedMulti(events));
+                } else {
+                    let value = T::from_str(&data.value)
+                        .ok()
+                        .or_else(|| T::from_str(&data.index.to_string()).ok());
+                    if let Some(value) = value {
+                        ctx.props().onvalue.emit(value);
+                    }
+                }
+            }
+            SelectMsg::ChangedMulti(events) => {
+                if let Some(select) = &self.inner {
+                    // Reopen the menu so additional rows can be picked; MDC
+                    // closes it after every native selection.
+                    select.open();
+                }
+                ctx.props().onchange_multi.emit(events);
+            }
+            SelectMsg::CheckValidity => {
+                let value = self
+                    .inner
+                    .as_ref()
+                    .map(|select| select.value())
+                    .unwrap_or_default();
+                self.apply_validity(ctx, &value);
+            }
         }
-        false
+        true
     }
 
     fn view(&self, ctx: &Context<Self>) -> Html {
+        let with_leading_icon = ctx.props().leading_icon.is_some()
+            || ctx.props().children.iter().any(|item| {
+                matches!(item.props.graphic, GraphicType::Icon | GraphicType::Avatar)
+            });
         let classes = classes![
             "mdc-select",
             "mdc-select--filled",
             ctx.props().select_width_class.clone(),
-            ctx.props().label.is_none().as_some("mdc-select--no-label")
+            ctx.props().label.is_none().as_some("mdc-select--no-label"),
+            with_leading_icon.as_some("mdc-select--with-leading-icon")
         ];
+        let leading_icon = ctx.props().leading_icon.map(|icon| {
+            html! {
+                <i class="mdc-select__icon material-icons">{ icon }</i>
+            }
+        });
         let menu_classes = classes![
             "mdc-menu",
             "mdc-menu-surface",
@@ -105,6 +329,7 @@ impl Component for Select {
         ];
         let label_id = format!("{}-label", &ctx.props().id);
         let selected_text_id = format!("{}-selected-text", &ctx.props().id);
+        let helper_text_id = format!("{}-helper-text", &ctx.props().id);
         let label = if ctx.props().label.is_none() {
             html! {}
         } else {
@@ -114,15 +339,43 @@ impl Component for Select {
                 </span>
             }
         };
+        let invalid = !self.validity.valid;
+        let selected_values = &self.selected_values;
+        let render_item = move |item: yew::virtual_dom::VChild<Item>| -> Html {
+            if ctx.props().multiple {
+                let mut props = (*item.props).clone();
+                props.selected = selected_values.contains(&props.value);
+                html! { <Item ..props /> }
+            } else {
+                item.into()
+            }
+        };
+        let helper_text = if invalid {
+            let helper_classes = classes![
+                "mdc-select-helper-text",
+                "mdc-select-helper-text--validation-msg"
+            ];
+            html! {
+                <div class="mdc-select-helper-text-container">
+                    <div class={helper_classes} id={helper_text_id.clone()} aria-hidden="false">
+                        { ctx.props().validation_message.clone().unwrap_or_default() }
+                    </div>
+                </div>
+            }
+        } else {
+            html! {}
+        };
         html! {
             <div id={ctx.props().id.clone()} class={classes} ref={self.node_ref.clone()}>
                 <div class="mdc-select__anchor"
                     role="button"
                     aria-haspopup="listbox"
                     aria-expanded="false"
-                    aria-labelledby={ format!("{} {}", &label_id, &selected_text_id)}>
+                    aria-labelledby={ format!("{} {}", &label_id, &selected_text_id)}
+                    aria-describedby={invalid.as_some(helper_text_id.clone())}>
 
                     <span class="mdc-select__ripple"></span>
+                    { for leading_icon }
                     { label }
                     <span class="mdc-select__selected-text-container">
                         <span id={selected_text_id} class="mdc-select__selected-text"></span>
@@ -150,10 +403,12 @@ impl Component for Select {
                 <div class={menu_classes}>
                     <ul class="mdc-deprecated-list"
                         role="listbox"
+                        aria-multiselectable={ctx.props().multiple.as_some("true")}
                         aria-label={format!("{} listbox", ctx.props().label.unwrap_or_default())}>
-                        { ctx.props().children.clone() }
+                        { for ctx.props().children.iter().map(render_item) }
                     </ul>
                 </div>
+                { helper_text }
             </div>
         }
     }
@@ -165,3 +420,78 @@ impl Component for Select {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validity_state_defaults_to_valid() {
+        assert_eq!(
+            ValidityState::default(),
+            ValidityState {
+                value_missing: false,
+                custom_error: false,
+                valid: true,
+            }
+        );
+    }
+
+    #[test]
+    fn native_validity_missing_value_is_invalid() {
+        let native = NativeValidityState {
+            value_missing: true,
+        };
+        assert_eq!(
+            ValidityState::from(native),
+            ValidityState {
+                value_missing: true,
+                custom_error: false,
+                valid: false,
+            }
+        );
+    }
+
+    #[test]
+    fn native_validity_present_value_is_valid() {
+        let native = NativeValidityState {
+            value_missing: false,
+        };
+        assert_eq!(
+            ValidityState::from(native),
+            ValidityState {
+                value_missing: false,
+                custom_error: false,
+                valid: true,
+            }
+        );
+    }
+
+    #[test]
+    fn add_selected_value_is_idempotent() {
+        let mut values = vec!["a".to_string()];
+        add_selected_value(&mut values, "a".to_string());
+        assert_eq!(values, vec!["a".to_string()]);
+    }
+
+    #[test]
+    fn add_selected_value_appends_new_entries() {
+        let mut values = vec!["a".to_string()];
+        add_selected_value(&mut values, "b".to_string());
+        assert_eq!(values, vec!["a".to_string(), "b".to_string()]);
+    }
+
+    #[test]
+    fn remove_selected_value_drops_matching_entries() {
+        let mut values = vec!["a".to_string(), "b".to_string()];
+        remove_selected_value(&mut values, "a");
+        assert_eq!(values, vec!["b".to_string()]);
+    }
+
+    #[test]
+    fn remove_selected_value_is_a_no_op_when_absent() {
+        let mut values = vec!["a".to_string()];
+        remove_selected_value(&mut values, "b");
+        assert_eq!(values, vec!["a".to_string()]);
+    }
+}
@@ -0,0 +1,95 @@
+use boolinator::Boolinator;
+use yew::prelude::*;
+
+/// The kind of graphic rendered in a select item's row, mirroring the
+/// related list-item components' `GraphicType`: `Icon` and `Avatar` render a
+/// leading graphic before the text, `Meta` renders a trailing meta span.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum GraphicType {
+    Null,
+    Icon,
+    Avatar,
+    Meta,
+}
+
+impl Default for GraphicType {
+    fn default() -> Self {
+        GraphicType::Null
+    }
+}
+
+pub struct Item;
+
+#[derive(Properties, Clone, PartialEq)]
+pub struct ItemProps {
+    pub value: String,
+    pub children: Children,
+    #[prop_or_default]
+    pub selected: bool,
+    #[prop_or_default]
+    pub graphic: GraphicType,
+    #[prop_or_default]
+    pub graphic_content: Option<String>,
+}
+
+impl Component for Item {
+    type Message = ();
+    type Properties = ItemProps;
+
+    fn create(_ctx: &Context<Self>) -> Self {
+        Self
+    }
+
+    fn changed(&mut self, _ctx: &Context<Self>) -> bool {
+        true
+    }
+
+    fn update(&mut self, _ctx: &Context<Self>, _msg: Self::Message) -> bool {
+        false
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let classes = classes![
+            "mdc-deprecated-list-item",
+            ctx.props()
+                .selected
+                .as_some("mdc-deprecated-list-item--selected")
+        ];
+        let graphic = match ctx.props().graphic {
+            GraphicType::Null => html! {},
+            GraphicType::Meta => html! {},
+            GraphicType::Icon => html! {
+                <span class="mdc-deprecated-list-item__graphic material-icons" aria-hidden="true">
+                    { ctx.props().graphic_content.clone().unwrap_or_default() }
+                </span>
+            },
+            GraphicType::Avatar => html! {
+                <img
+                    class="mdc-deprecated-list-item__graphic"
+                    src={ctx.props().graphic_content.clone().unwrap_or_default()}
+                    alt="" />
+            },
+        };
+        let meta = if ctx.props().graphic == GraphicType::Meta {
+            html! {
+                <span class="mdc-deprecated-list-item__meta">
+                    { ctx.props().graphic_content.clone().unwrap_or_default() }
+                </span>
+            }
+        } else {
+            html! {}
+        };
+        html! {
+            <li class={classes}
+                data-value={ctx.props().value.clone()}
+                role="option"
+                aria-selected={ctx.props().selected.to_string()}>
+                { graphic }
+                <span class="mdc-deprecated-list-item__text">
+                    { ctx.props().children.clone() }
+                </span>
+                { meta }
+            </li>
+        }
+    }
+}
@@ -0,0 +1,66 @@
+use wasm_bindgen::prelude::*;
+use web_sys::Element;
+
+#[wasm_bindgen]
+extern "C" {
+    pub type MDCSelect;
+
+    #[wasm_bindgen(constructor, js_class = "MDCSelect")]
+    pub fn new(element: Element) -> MDCSelect;
+
+    #[wasm_bindgen(method, js_name = listen)]
+    pub fn listen(
+        this: &MDCSelect,
+        event_type: &str,
+        listener: &Closure<dyn FnMut(web_sys::CustomEvent)>,
+    );
+
+    #[wasm_bindgen(method, js_name = unlisten)]
+    pub fn unlisten(
+        this: &MDCSelect,
+        event_type: &str,
+        listener: &Closure<dyn FnMut(web_sys::CustomEvent)>,
+    );
+
+    #[wasm_bindgen(method, getter = value)]
+    pub fn value(this: &MDCSelect) -> String;
+
+    #[wasm_bindgen(method, setter = value)]
+    pub fn set_value(this: &MDCSelect, value: &str);
+
+    #[wasm_bindgen(method, setter = valid)]
+    pub fn set_valid(this: &MDCSelect, valid: bool);
+
+    /// Re-opens the menu surface, e.g. after a pick so a `multiple` select
+    /// can keep accumulating selections.
+    #[wasm_bindgen(method, js_name = open)]
+    pub fn open(this: &MDCSelect);
+
+    #[wasm_bindgen(method, js_name = destroy)]
+    pub fn destroy(this: &MDCSelect);
+
+    pub type MDCDrawer;
+
+    #[wasm_bindgen(constructor, js_class = "MDCDrawer")]
+    pub fn new(element: Element) -> MDCDrawer;
+
+    #[wasm_bindgen(method, setter = open)]
+    pub fn set_open(this: &MDCDrawer, open: bool);
+
+    #[wasm_bindgen(method, js_name = listen)]
+    pub fn listen(
+        this: &MDCDrawer,
+        event_type: &str,
+        listener: &Closure<dyn FnMut(web_sys::Event)>,
+    );
+
+    #[wasm_bindgen(method, js_name = unlisten)]
+    pub fn unlisten(
+        this: &MDCDrawer,
+        event_type: &str,
+        listener: &Closure<dyn FnMut(web_sys::Event)>,
+    );
+
+    #[wasm_bindgen(method, js_name = destroy)]
+    pub fn destroy(this: &MDCDrawer);
+}